@@ -16,9 +16,14 @@ use reth_rpc_eth_api::{
 };
 use reth_rpc_eth_types::{utils::recover_raw_transaction, EthStateCache};
 use reth_transaction_pool::{PoolTransaction, TransactionOrigin, TransactionPool};
+use std::time::Duration;
 
 use crate::{OpEthApi, SequencerClient};
 
+/// Bound on how long we wait for the sequencer to answer an on-demand transaction fetch before
+/// giving up and reporting the transaction as unknown.
+const SEQUENCER_TX_FETCH_TIMEOUT: Duration = Duration::from_secs(2);
+
 impl<N> EthTransactions for OpEthApi<N>
 where
     Self: LoadTransaction<NetworkTypes: Network<TransactionResponse = Transaction>>,
@@ -36,7 +41,7 @@ where
         &self,
         hash: B256,
     ) -> Result<Option<RpcTransaction<Self::NetworkTypes>>, Self::Error> {
-        Ok(LoadTransaction::transaction_by_hash(self, hash).await?.map(|tx| {
+        if let Some(tx) = LoadTransaction::transaction_by_hash(self, hash).await? {
             let is_deposit = tx.as_recovered().is_deposit();
             let mut tx = tx.into_transaction::<Self::TransactionCompat>();
             // deposit receipt version for given transaction, if the block number is known
@@ -52,8 +57,31 @@ where
                     .then_some(1);
             }
 
-            tx
-        }))
+            return Ok(Some(tx));
+        }
+
+        // The transaction isn't known locally yet. If the sequencer accepted it, it won't show
+        // up in our pool or database until it propagates, so ask the sequencer directly for it
+        // via the standard `eth_getTransactionByHash`, mirroring the light-client pattern of
+        // fetching an object from an upstream on a local miss.
+        let Some(client) = self.raw_tx_forwarder() else { return Ok(None) };
+
+        match tokio::time::timeout(
+            SEQUENCER_TX_FETCH_TIMEOUT,
+            client.eth_get_transaction_by_hash::<RpcTransaction<Self::NetworkTypes>>(hash),
+        )
+        .await
+        {
+            Ok(Ok(tx)) => Ok(tx),
+            Ok(Err(err)) => {
+                tracing::debug!(target: "rpc::eth", %err, %hash, "failed to fetch transaction from sequencer");
+                Ok(None)
+            }
+            Err(_) => {
+                tracing::debug!(target: "rpc::eth", %hash, "timed out fetching transaction from sequencer");
+                Ok(None)
+            }
+        }
     }
 
     async fn send_raw_transaction(&self, tx: Bytes) -> Result<B256, Self::Error> {
@@ -62,12 +90,16 @@ where
             <Self::Pool as TransactionPool>::Transaction::from_pooled(recovered.into());
 
         // On optimism, transactions are forwarded directly to the sequencer to be included in
-        // blocks that it builds.
+        // blocks that it builds. The forwarder fails over across its configured endpoints on
+        // its own; in strict mode it reports an error once every endpoint is exhausted instead
+        // of letting this fall through to local-only handling.
         if let Some(client) = self.raw_tx_forwarder().as_ref() {
-            tracing::debug!( target: "rpc::eth",  "forwarding raw transaction to");
-            let _ = client.forward_raw_transaction(&tx).await.inspect_err(|err| {
-                    tracing::debug!(target: "rpc::eth", %err, hash=% *pool_transaction.hash(), "failed to forward raw transaction");
-                });
+            if let Err(err) = client.forward_raw_transaction(&tx).await {
+                tracing::debug!(target: "rpc::eth", %err, hash=% *pool_transaction.hash(), "failed to forward raw transaction to any sequencer endpoint");
+                if client.is_strict() {
+                    return Err(Self::Error::from_eth_err(err));
+                }
+            }
         }
 
         // submit the transaction to the pool with a `Local` origin
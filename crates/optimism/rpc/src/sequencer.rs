@@ -0,0 +1,585 @@
+//! A client for forwarding transactions to, and fetching transactions from, a rollup sequencer
+//! over its HTTP JSON-RPC API.
+
+use alloy_primitives::{Bytes, B256};
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// Flow-control parameters governing how much `eth_sendRawTransaction` traffic a single
+/// sequencer endpoint is allowed before it gets skipped in favor of the next one.
+///
+/// Modeled after the credit-based flow control used by the LES (light client) protocol: every
+/// endpoint starts with `cap` credits, recharges linearly over time up to `cap`, and every
+/// forwarded transaction costs `base_cost` plus `cost_per_byte` times the encoded transaction
+/// size.
+#[derive(Debug, Clone, Copy)]
+pub struct CreditConfig {
+    /// Flat cost charged for every forwarded transaction, regardless of size.
+    pub base_cost: f64,
+    /// Additional cost charged per byte of the encoded transaction.
+    pub cost_per_byte: f64,
+    /// Maximum credit balance an endpoint can accrue.
+    pub cap: f64,
+    /// Credits recharged per second, up to `cap`.
+    pub recharge_per_sec: f64,
+}
+
+impl Default for CreditConfig {
+    fn default() -> Self {
+        Self { base_cost: 1.0, cost_per_byte: 0.001, cap: 100.0, recharge_per_sec: 10.0 }
+    }
+}
+
+impl CreditConfig {
+    fn cost_of(&self, tx: &Bytes) -> f64 {
+        self.base_cost + self.cost_per_byte * tx.len() as f64
+    }
+}
+
+/// A linearly-recharging credit balance for a single sequencer endpoint.
+#[derive(Debug)]
+struct FlowControlCredits {
+    balance: f64,
+    last_recharge: Instant,
+}
+
+impl FlowControlCredits {
+    fn new(cap: f64) -> Self {
+        Self { balance: cap, last_recharge: Instant::now() }
+    }
+
+    fn recharge(&mut self, config: &CreditConfig) {
+        let elapsed = self.last_recharge.elapsed().as_secs_f64();
+        self.balance = (self.balance + elapsed * config.recharge_per_sec).min(config.cap);
+        self.last_recharge = Instant::now();
+    }
+
+    /// Recharges, then deducts `cost` if the balance can cover it.
+    ///
+    /// Returns `false` (without deducting) if the balance is insufficient.
+    fn deduct_cost(&mut self, cost: f64, config: &CreditConfig) -> bool {
+        self.recharge(config);
+        if self.balance < cost {
+            return false;
+        }
+        self.balance -= cost;
+        true
+    }
+}
+
+/// A single sequencer endpoint together with its credit balance and success/failure counters.
+#[derive(Debug)]
+struct SequencerEndpoint {
+    url: String,
+    credits: Mutex<FlowControlCredits>,
+    successes: AtomicU64,
+    failures: AtomicU64,
+}
+
+impl SequencerEndpoint {
+    fn new(url: String, cap: f64) -> Self {
+        Self {
+            url,
+            credits: Mutex::new(FlowControlCredits::new(cap)),
+            successes: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+        }
+    }
+
+    /// Creates a new endpoint carrying the same url, credit balance, and success/failure
+    /// counters as `self`, decoupled from whichever [`SequencerClient`] clone `self` belongs to.
+    fn snapshot(&self) -> Self {
+        let credits = self.credits.lock().unwrap_or_else(|e| e.into_inner());
+        Self {
+            url: self.url.clone(),
+            credits: Mutex::new(FlowControlCredits {
+                balance: credits.balance,
+                last_recharge: credits.last_recharge,
+            }),
+            successes: AtomicU64::new(self.successes.load(Ordering::Relaxed)),
+            failures: AtomicU64::new(self.failures.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// Point-in-time metrics for a single sequencer endpoint.
+#[derive(Debug, Clone)]
+pub struct EndpointMetrics {
+    /// The endpoint this snapshot describes.
+    pub endpoint: String,
+    /// Number of transactions successfully forwarded to this endpoint.
+    pub successes: u64,
+    /// Number of forwarding attempts that failed or were skipped for lack of credit.
+    pub failures: u64,
+    /// Credits currently available to this endpoint.
+    pub remaining_credits: f64,
+}
+
+/// A client for interacting with a rollup sequencer's JSON-RPC API.
+///
+/// Holds an ordered list of sequencer endpoints and forwards `eth_sendRawTransaction` calls to
+/// them with failover: each endpoint is tried in order, skipping any that don't have enough
+/// flow-control credit, so a single overloaded or unreachable sequencer doesn't stall forwarding.
+/// Also used to fetch transactions the sequencer has accepted but not yet propagated to this
+/// node's mempool.
+#[derive(Debug, Clone)]
+pub struct SequencerClient {
+    inner: Arc<SequencerClientInner>,
+}
+
+#[derive(Debug)]
+struct SequencerClientInner {
+    http_client: Client,
+    endpoints: Vec<SequencerEndpoint>,
+    credit_config: CreditConfig,
+    /// If `true`, [`SequencerClient::forward_raw_transaction`] returns an error when every
+    /// endpoint is exhausted instead of silently giving up.
+    strict: AtomicBool,
+    id: AtomicUsize,
+}
+
+impl SequencerClient {
+    /// Creates a new [`SequencerClient`] that talks to a single `sequencer_endpoint`.
+    pub fn new(sequencer_endpoint: impl Into<String>) -> Self {
+        Self::with_endpoints(vec![sequencer_endpoint.into()])
+    }
+
+    /// Creates a new [`SequencerClient`] that forwards to `endpoints` in order, failing over to
+    /// the next endpoint when the current one is out of credit or errors.
+    pub fn with_endpoints(endpoints: Vec<String>) -> Self {
+        let credit_config = CreditConfig::default();
+        let http_client =
+            Client::builder().timeout(Duration::from_secs(2)).build().unwrap_or_default();
+        let endpoints =
+            endpoints.into_iter().map(|url| SequencerEndpoint::new(url, credit_config.cap)).collect();
+
+        Self {
+            inner: Arc::new(SequencerClientInner {
+                http_client,
+                endpoints,
+                credit_config,
+                strict: AtomicBool::new(false),
+                id: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// Sets the credit accounting parameters used for flow control across all endpoints.
+    ///
+    /// Resets every endpoint's credit balance to the new cap.
+    #[must_use]
+    pub fn with_credit_config(self, credit_config: CreditConfig) -> Self {
+        self.rebuild(|inner| SequencerClientInner {
+            http_client: inner.http_client.clone(),
+            endpoints: inner
+                .endpoints
+                .iter()
+                .map(|e| SequencerEndpoint::new(e.url.clone(), credit_config.cap))
+                .collect(),
+            credit_config,
+            strict: AtomicBool::new(inner.strict.load(Ordering::Relaxed)),
+            id: AtomicUsize::new(0),
+        })
+    }
+
+    /// Configures whether forwarding should return an error when every endpoint is exhausted,
+    /// rather than silently accepting the transaction into the local pool only.
+    ///
+    /// Like [`Self::with_credit_config`], this rebuilds into a fresh `Arc<SequencerClientInner>`
+    /// so that other outstanding clones of this [`SequencerClient`] keep their own strict-mode
+    /// setting. Unlike [`Self::with_credit_config`], it carries over every endpoint's accrued
+    /// credit balance and [`Self::metrics`] counters instead of resetting them.
+    #[must_use]
+    pub fn with_strict_mode(self, strict: bool) -> Self {
+        self.rebuild(|inner| SequencerClientInner {
+            http_client: inner.http_client.clone(),
+            endpoints: inner.endpoints.iter().map(SequencerEndpoint::snapshot).collect(),
+            credit_config: inner.credit_config,
+            strict: AtomicBool::new(strict),
+            id: AtomicUsize::new(inner.id.load(Ordering::Relaxed)),
+        })
+    }
+
+    fn rebuild(mut self, f: impl FnOnce(&SequencerClientInner) -> SequencerClientInner) -> Self {
+        let new_inner = f(&self.inner);
+        self.inner = Arc::new(new_inner);
+        self
+    }
+
+    /// Returns whether strict mode is enabled; see [`Self::with_strict_mode`].
+    pub fn is_strict(&self) -> bool {
+        self.inner.strict.load(Ordering::Relaxed)
+    }
+
+    /// Returns the configured sequencer endpoints, in priority order.
+    pub fn endpoints(&self) -> impl Iterator<Item = &str> {
+        self.inner.endpoints.iter().map(|e| e.url.as_str())
+    }
+
+    /// Returns a snapshot of success/failure counts and remaining credit for every endpoint.
+    pub fn metrics(&self) -> Vec<EndpointMetrics> {
+        self.inner
+            .endpoints
+            .iter()
+            .map(|endpoint| EndpointMetrics {
+                endpoint: endpoint.url.clone(),
+                successes: endpoint.successes.load(Ordering::Relaxed),
+                failures: endpoint.failures.load(Ordering::Relaxed),
+                remaining_credits: {
+                    let mut credits = endpoint.credits.lock().unwrap_or_else(|e| e.into_inner());
+                    credits.recharge(&self.inner.credit_config);
+                    credits.balance
+                },
+            })
+            .collect()
+    }
+
+    /// Forwards a raw transaction to `eth_sendRawTransaction` on the first endpoint with enough
+    /// credit, failing over to the next endpoint on error or credit exhaustion.
+    ///
+    /// Returns an error if every endpoint was skipped or failed and [`Self::is_strict`] is
+    /// `true`; otherwise returns `Ok(())` even if no endpoint accepted the transaction, so the
+    /// caller can still fall back to local-only handling.
+    pub async fn forward_raw_transaction(&self, tx: &Bytes) -> Result<(), SequencerClientError> {
+        let cost = self.inner.credit_config.cost_of(tx);
+        let mut last_err = None;
+
+        for endpoint in &self.inner.endpoints {
+            let allowed = {
+                let mut credits = endpoint.credits.lock().unwrap_or_else(|e| e.into_inner());
+                credits.deduct_cost(cost, &self.inner.credit_config)
+            };
+
+            if !allowed {
+                tracing::debug!(target: "rpc::eth", url = %endpoint.url, "sequencer endpoint out of credit, skipping");
+                endpoint.failures.fetch_add(1, Ordering::Relaxed);
+                last_err.get_or_insert(SequencerClientError::CreditExhausted);
+                continue;
+            }
+
+            match self
+                .call::<Value>(&endpoint.url, "eth_sendRawTransaction", json!([tx.to_string()]))
+                .await
+            {
+                Ok(_) => {
+                    endpoint.successes.fetch_add(1, Ordering::Relaxed);
+                    return Ok(());
+                }
+                Err(err) => {
+                    tracing::debug!(target: "rpc::eth", url = %endpoint.url, %err, "sequencer endpoint rejected transaction, trying next");
+                    endpoint.failures.fetch_add(1, Ordering::Relaxed);
+                    last_err = Some(err);
+                }
+            }
+        }
+
+        if self.inner.strict.load(Ordering::Relaxed) {
+            return Err(last_err.unwrap_or(SequencerClientError::NoEndpointsConfigured));
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the transaction by hash directly from the sequencer via the standard
+    /// `eth_getTransactionByHash` JSON-RPC method, for transactions the sequencer has accepted
+    /// but that haven't propagated to this node's pool yet.
+    ///
+    /// Mirrors the light-client pattern of fetching an object from an upstream peer when it
+    /// isn't held locally: the request is bounded by the client's configured timeout and is
+    /// safe to cancel. Always queries the highest-priority endpoint.
+    ///
+    /// `eth_getTransactionByHash` is part of the standard JSON-RPC surface every op-node/op-geth
+    /// sequencer exposes, unlike `eth_getRawTransactionByHash`, which is a debug/vendor
+    /// extension many sequencers don't implement; falling back to that would have silently
+    /// no-op'd against exactly the sequencers this method exists to cover. `T` is left generic
+    /// so this crate doesn't need to depend on `op_alloy_rpc_types`; callers deserialize into
+    /// whatever RPC transaction type they need.
+    pub async fn eth_get_transaction_by_hash<T: DeserializeOwned>(
+        &self,
+        hash: B256,
+    ) -> Result<Option<T>, SequencerClientError> {
+        let endpoint =
+            self.inner.endpoints.first().ok_or(SequencerClientError::NoEndpointsConfigured)?;
+        self.call_optional(&endpoint.url, "eth_getTransactionByHash", json!([hash])).await
+    }
+
+    async fn call<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        method: &'static str,
+        params: Value,
+    ) -> Result<T, SequencerClientError> {
+        let id = self.inner.id.fetch_add(1, Ordering::SeqCst);
+        let body = JsonRpcRequest { jsonrpc: "2.0", method, params, id };
+
+        let response: JsonRpcResponse<T> = self
+            .inner
+            .http_client
+            .post(endpoint)
+            .json(&body)
+            .send()
+            .await
+            .map_err(SequencerClientError::Http)?
+            .json()
+            .await
+            .map_err(SequencerClientError::Http)?;
+
+        if let Some(error) = response.error {
+            return Err(SequencerClientError::Rpc(error.message));
+        }
+
+        response.result.ok_or(SequencerClientError::MissingResult)
+    }
+
+    /// Like [`Self::call`], but treats a missing or `null` `result` as `Ok(None)` instead of
+    /// [`SequencerClientError::MissingResult`], for JSON-RPC methods that legitimately respond
+    /// with `null` (e.g. `eth_getTransactionByHash` for a hash the sequencer doesn't know).
+    async fn call_optional<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        method: &'static str,
+        params: Value,
+    ) -> Result<Option<T>, SequencerClientError> {
+        match self.call::<T>(endpoint, method, params).await {
+            Ok(result) => Ok(Some(result)),
+            Err(SequencerClientError::MissingResult) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRpcRequest<'a> {
+    jsonrpc: &'a str,
+    method: &'a str,
+    params: Value,
+    id: usize,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcError {
+    message: String,
+}
+
+/// Errors produced while talking to a [`SequencerClient`].
+#[derive(Debug, thiserror::Error)]
+pub enum SequencerClientError {
+    /// The HTTP request to the sequencer failed or timed out.
+    #[error("sequencer request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    /// The sequencer returned a JSON-RPC error.
+    #[error("sequencer returned an error: {0}")]
+    Rpc(String),
+    /// The sequencer's response had neither a result nor an error.
+    #[error("sequencer response was missing a result")]
+    MissingResult,
+    /// Every configured endpoint was out of flow-control credit.
+    #[error("all sequencer endpoints are out of credit")]
+    CreditExhausted,
+    /// No sequencer endpoints are configured at all.
+    #[error("no sequencer endpoints configured")]
+    NoEndpointsConfigured,
+}
+
+impl From<SequencerClientError> for reth_rpc_eth_types::EthApiError {
+    fn from(err: SequencerClientError) -> Self {
+        Self::Other(Box::new(err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    fn test_credit_config(base_cost: f64, cost_per_byte: f64, cap: f64, recharge_per_sec: f64) -> CreditConfig {
+        CreditConfig { base_cost, cost_per_byte, cap, recharge_per_sec }
+    }
+
+    #[test]
+    fn cost_of_charges_base_cost_plus_per_byte() {
+        let config = test_credit_config(1.0, 0.1, 100.0, 10.0);
+        assert_eq!(config.cost_of(&Bytes::from_static(b"")), 1.0);
+        assert_eq!(config.cost_of(&Bytes::from_static(b"aaaaaaaaaa")), 2.0);
+    }
+
+    #[test]
+    fn recharge_accrues_credits_proportional_to_elapsed_time() {
+        let config = test_credit_config(1.0, 0.0, 100.0, 10.0);
+        let mut credits =
+            FlowControlCredits { balance: 50.0, last_recharge: Instant::now() - Duration::from_secs(2) };
+
+        credits.recharge(&config);
+
+        assert!((credits.balance - 70.0).abs() < 0.5, "balance was {}", credits.balance);
+    }
+
+    #[test]
+    fn recharge_does_not_exceed_cap() {
+        let config = test_credit_config(1.0, 0.0, 100.0, 10.0);
+        let mut credits =
+            FlowControlCredits { balance: 95.0, last_recharge: Instant::now() - Duration::from_secs(10) };
+
+        credits.recharge(&config);
+
+        assert_eq!(credits.balance, 100.0);
+    }
+
+    #[test]
+    fn deduct_cost_subtracts_balance_when_sufficient() {
+        let config = test_credit_config(1.0, 0.0, 100.0, 10.0);
+        let mut credits = FlowControlCredits::new(100.0);
+
+        assert!(credits.deduct_cost(10.0, &config));
+        assert!((credits.balance - 90.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn deduct_cost_fails_without_deducting_when_balance_insufficient() {
+        let config = test_credit_config(1.0, 0.0, 100.0, 0.0);
+        let mut credits = FlowControlCredits::new(5.0);
+
+        assert!(!credits.deduct_cost(10.0, &config));
+        assert_eq!(credits.balance, 5.0);
+    }
+
+    /// Spawns a one-shot stub JSON-RPC server on `127.0.0.1` that replies to a single request
+    /// with `body`, returning its URL.
+    fn spawn_stub_sequencer(body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind stub sequencer");
+        let addr = listener.local_addr().expect("local addr");
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    #[tokio::test]
+    async fn forward_raw_transaction_skips_endpoint_with_insufficient_credit() {
+        let stub_url = spawn_stub_sequencer(r#"{"jsonrpc":"2.0","id":0,"result":"0x1"}"#);
+        let client =
+            SequencerClient::with_endpoints(vec!["http://127.0.0.1:9".to_string(), stub_url]);
+        client.inner.endpoints[0].credits.lock().unwrap_or_else(|e| e.into_inner()).balance = 0.0;
+
+        let result = client.forward_raw_transaction(&Bytes::from_static(b"tx")).await;
+
+        assert!(result.is_ok());
+        let metrics = client.metrics();
+        assert_eq!(metrics[0].failures, 1);
+        assert_eq!(metrics[0].successes, 0);
+        assert_eq!(metrics[1].successes, 1);
+    }
+
+    #[tokio::test]
+    async fn forward_raw_transaction_errors_in_strict_mode_when_all_endpoints_exhausted() {
+        let client = SequencerClient::with_endpoints(vec!["http://127.0.0.1:9".to_string()])
+            .with_strict_mode(true);
+        client.inner.endpoints[0].credits.lock().unwrap_or_else(|e| e.into_inner()).balance = 0.0;
+
+        let result = client.forward_raw_transaction(&Bytes::from_static(b"tx")).await;
+
+        assert!(matches!(result, Err(SequencerClientError::CreditExhausted)));
+    }
+
+    #[test]
+    fn with_strict_mode_does_not_affect_other_clones() {
+        let client = SequencerClient::with_endpoints(vec!["http://127.0.0.1:9".to_string()]);
+        let other_clone = client.clone();
+
+        let strict_client = client.with_strict_mode(true);
+
+        assert!(strict_client.is_strict());
+        assert!(!other_clone.is_strict());
+    }
+
+    #[test]
+    fn with_strict_mode_preserves_credit_balances_and_metrics() {
+        let client = SequencerClient::with_endpoints(vec!["http://127.0.0.1:9".to_string()]);
+        client.inner.endpoints[0].credits.lock().unwrap_or_else(|e| e.into_inner()).balance = 7.0;
+        client.inner.endpoints[0].successes.store(3, Ordering::Relaxed);
+        client.inner.endpoints[0].failures.store(2, Ordering::Relaxed);
+
+        let strict_client = client.with_strict_mode(true);
+
+        let metrics = strict_client.metrics();
+        assert!((metrics[0].remaining_credits - 7.0).abs() < 0.01, "{}", metrics[0].remaining_credits);
+        assert_eq!(metrics[0].successes, 3);
+        assert_eq!(metrics[0].failures, 2);
+    }
+
+    #[tokio::test]
+    async fn eth_get_transaction_by_hash_returns_tx_on_hit() {
+        let stub_url = spawn_stub_sequencer(
+            r#"{"jsonrpc":"2.0","id":0,"result":{"hash":"0x11","blockNumber":null}}"#,
+        );
+        let client = SequencerClient::with_endpoints(vec![stub_url]);
+
+        let tx = client
+            .eth_get_transaction_by_hash::<Value>(B256::repeat_byte(0x11))
+            .await
+            .expect("request should succeed");
+
+        assert_eq!(tx, Some(json!({"hash": "0x11", "blockNumber": null})));
+    }
+
+    #[tokio::test]
+    async fn eth_get_transaction_by_hash_returns_none_on_miss() {
+        let stub_url = spawn_stub_sequencer(r#"{"jsonrpc":"2.0","id":0,"result":null}"#);
+        let client = SequencerClient::with_endpoints(vec![stub_url]);
+
+        let tx = client
+            .eth_get_transaction_by_hash::<Value>(B256::repeat_byte(0x22))
+            .await
+            .expect("a null result is not an error");
+
+        assert_eq!(tx, None);
+    }
+
+    #[tokio::test]
+    async fn eth_get_transaction_by_hash_can_be_bounded_by_a_caller_timeout() {
+        // A listener that accepts the connection but never writes a response, standing in for
+        // an unresponsive sequencer; callers (like `OpEthApi::transaction_by_hash`) wrap this
+        // call in their own `tokio::time::timeout`.
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind stub sequencer");
+        let addr = listener.local_addr().expect("local addr");
+        std::thread::spawn(move || {
+            if let Ok((stream, _)) = listener.accept() {
+                std::thread::sleep(Duration::from_secs(5));
+                drop(stream);
+            }
+        });
+        let client = SequencerClient::with_endpoints(vec![format!("http://{addr}")]);
+
+        let result = tokio::time::timeout(
+            Duration::from_millis(50),
+            client.eth_get_transaction_by_hash::<Value>(B256::repeat_byte(0x33)),
+        )
+        .await;
+
+        assert!(result.is_err(), "expected the caller's timeout to fire first");
+    }
+}
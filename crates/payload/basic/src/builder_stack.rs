@@ -2,6 +2,7 @@ use crate::{
     BuildArguments, BuildOutcome, PayloadBuilder, PayloadBuilderError,
     PayloadConfig, PayloadBuilderAttributes
 };
+use crate::registry::ProposerRegistry;
 
 use alloy_primitives::{Address, B256};
 use reth_payload_builder::PayloadId;
@@ -10,6 +11,7 @@ use reth_primitives::{SealedBlock, Withdrawals, U256};
 
 use std::fmt;
 use std::error::Error;
+use std::sync::Arc;
 
 /// hand rolled Either enum to handle two builder types
 #[derive(Debug, Clone)]
@@ -116,29 +118,176 @@ impl<L, R> PayloadBuilderAttributes for Either<L, R>
        }
    }
 
+/// Converts between `L`'s and `R`'s payload attributes so [`StackMode::Competitive`] can build
+/// both sides against the same job from a single incoming attribute variant.
+///
+/// [`PayloadBuilderStack::new`] uses [`NoConversion`], whose methods are never called because
+/// [`StackMode::Selective`] never needs to convert between attribute types; pass a real
+/// implementation to [`PayloadBuilderStack::competitive`], which converts on every build.
+pub trait AttributeConverter<LA, RA> {
+    /// Builds `RA` from `LA`.
+    fn left_to_right(&self, left: &LA) -> RA;
+    /// Builds `LA` from `RA`.
+    fn right_to_left(&self, right: &RA) -> LA;
+}
+
+/// Placeholder [`AttributeConverter`] used by [`PayloadBuilderStack::new`], where
+/// [`StackMode::Selective`] never converts between attribute types.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoConversion;
+
+impl<LA, RA> AttributeConverter<LA, RA> for NoConversion {
+    fn left_to_right(&self, _left: &LA) -> RA {
+        unreachable!("NoConversion is only valid in StackMode::Selective, which never converts attributes")
+    }
+
+    fn right_to_left(&self, _right: &RA) -> LA {
+        unreachable!("NoConversion is only valid in StackMode::Selective, which never converts attributes")
+    }
+}
+
 /// this structure enables the chaining of multiple `PayloadBuilder` implementations,
 /// creating a hierarchical fallback system. It's designed to be nestable, allowing
 /// for complex builder arrangements like `Stack<Stack<A, B>, C>` with different
 #[derive(Debug)]
-pub struct PayloadBuilderStack<L, R> {
+pub struct PayloadBuilderStack<L, R, Conv = NoConversion, Ovr = NoOverride> {
     left: L,
     right: R,
+    mode: StackMode,
+    registry: Option<Arc<ProposerRegistry>>,
+    converter: Conv,
+    overrider: Ovr,
+}
+
+/// Applies a [`ProposerRegistry`]'s fee-recipient/gas-limit override to `LA`'s and `RA`'s
+/// attributes.
+///
+/// No concrete implementation of this trait ships in this crate yet: [`NoOverride`] (a no-op) is
+/// the only one, so [`PayloadBuilderStack::with_registry`] is not called by any real attribute
+/// type today. Wiring one up needs attribute types that can both report which proposer a job's
+/// attributes were built for and accept a new fee recipient/gas limit after construction — and
+/// the standard Engine API `PayloadAttributes` this crate's `L`/`R` builders are generic over
+/// don't carry a proposer identity at all (that only shows up in the separate validator
+/// registration/relay-bid flow in [`crate::relay`]), so a real implementation also needs a way to
+/// thread the proposer pubkey for a job in from outside the attributes themselves. Until that's
+/// resolved, treat `with_registry` as unimplemented scaffolding rather than working behavior.
+pub trait RegistryOverride<LA, RA> {
+    /// Applies `registry`'s override to `left`'s proposer, if one is registered, clamping the
+    /// registered gas limit to the allowed band around `parent_gas_limit`.
+    fn override_left(&self, registry: &ProposerRegistry, left: LA, parent_gas_limit: u64) -> LA;
+
+    /// Applies `registry`'s override to `right`'s proposer, if one is registered, clamping the
+    /// registered gas limit to the allowed band around `parent_gas_limit`.
+    fn override_right(&self, registry: &ProposerRegistry, right: RA, parent_gas_limit: u64) -> RA;
 }
 
-impl<L, R> PayloadBuilderStack<L, R> {
+/// The only [`RegistryOverride`] this crate ships: a no-op. Used by [`PayloadBuilderStack::new`]
+/// and [`PayloadBuilderStack::competitive`], where no [`ProposerRegistry`] is attached and
+/// attributes are left unchanged. See [`RegistryOverride`]'s docs for why there is no
+/// fee-recipient/gas-limit-applying implementation to use instead yet.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoOverride;
+
+impl<LA, RA> RegistryOverride<LA, RA> for NoOverride {
+    fn override_left(&self, _registry: &ProposerRegistry, left: LA, _parent_gas_limit: u64) -> LA {
+        left
+    }
+
+    fn override_right(&self, _registry: &ProposerRegistry, right: RA, _parent_gas_limit: u64) -> RA {
+        right
+    }
+}
+
+/// Controls how a [`PayloadBuilderStack`] decides between its `left` and `right` builders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StackMode {
+    /// The incoming attribute variant hard-selects a single builder. If that builder fails,
+    /// the stack reports failure rather than engaging the other builder.
+    #[default]
+    Selective,
+    /// Both builders run against the same job, and the stack keeps whichever payload pays
+    /// the most, as measured by [`BuiltPayload::fees`]. If one builder errors, the other's
+    /// result is used instead of failing the whole job.
+    Competitive,
+}
+
+impl<L, R> PayloadBuilderStack<L, R, NoConversion, NoOverride> {
     /// Creates a new `PayloadBuilderStack` with the given left and right builders.
+    ///
+    /// The incoming attribute variant selects a single builder per job; see
+    /// [`Self::competitive`] to run both builders and keep the most profitable payload.
     pub const fn new(left: L, right: R) -> Self {
-        Self { left, right }
+        Self {
+            left,
+            right,
+            mode: StackMode::Selective,
+            registry: None,
+            converter: NoConversion,
+            overrider: NoOverride,
+        }
+    }
+}
+
+impl<L, R, Conv> PayloadBuilderStack<L, R, Conv, NoOverride> {
+    /// Creates a new `PayloadBuilderStack` that runs `left` and `right` against the same job
+    /// and returns whichever payload is more profitable, mirroring how block builders
+    /// maximize extractable value.
+    ///
+    /// `converter` builds each side's attributes from the other's; every build calls it once, to
+    /// drive the side the incoming attribute variant didn't originate from. [`NoConversion`]
+    /// panics when called, so it cannot be used here, even if `L` and `R` share the same
+    /// attribute type.
+    pub const fn competitive(left: L, right: R, converter: Conv) -> Self {
+        Self {
+            left,
+            right,
+            mode: StackMode::Competitive,
+            registry: None,
+            converter,
+            overrider: NoOverride,
+        }
     }
 }
 
-impl<L, R> Clone for PayloadBuilderStack<L, R>
+impl<L, R, Conv, Ovr> PayloadBuilderStack<L, R, Conv, Ovr> {
+    /// Attaches a [`ProposerRegistry`] and an `overrider` that applies its fee-recipient and
+    /// target-gas-limit overrides to attributes whose proposer is registered.
+    ///
+    /// There is no real `overrider` to pass yet — see [`RegistryOverride`]'s docs for why; until
+    /// one exists, callers can only attach [`NoOverride`], which makes this a no-op.
+    #[must_use]
+    pub fn with_registry<Ovr2>(
+        self,
+        registry: Arc<ProposerRegistry>,
+        overrider: Ovr2,
+    ) -> PayloadBuilderStack<L, R, Conv, Ovr2> {
+        PayloadBuilderStack {
+            left: self.left,
+            right: self.right,
+            mode: self.mode,
+            registry: Some(registry),
+            converter: self.converter,
+            overrider,
+        }
+    }
+}
+
+impl<L, R, Conv, Ovr> Clone for PayloadBuilderStack<L, R, Conv, Ovr>
 where
     L: Clone,
     R: Clone,
+    Conv: Clone,
+    Ovr: Clone,
 {
     fn clone(&self) -> Self {
-        Self::new(self.left.clone(), self.right.clone())
+        Self {
+            left: self.left.clone(),
+            right: self.right.clone(),
+            mode: self.mode,
+            registry: self.registry.clone(),
+            converter: self.converter.clone(),
+            overrider: self.overrider.clone(),
+        }
     }
 }
 
@@ -178,7 +327,7 @@ impl<B> BuildOutcome<B> {
     }
 }
 
-impl<L, R, Pool, Client> PayloadBuilder<Pool, Client> for PayloadBuilderStack<L, R>
+impl<L, R, Conv, Ovr, Pool, Client> PayloadBuilder<Pool, Client> for PayloadBuilderStack<L, R, Conv, Ovr>
 where
     L: PayloadBuilder<Pool, Client> + Unpin + 'static,
     R: PayloadBuilder<Pool, Client> + Unpin + 'static,
@@ -188,6 +337,8 @@ where
     R::Attributes: Unpin + Clone,
     L::BuiltPayload: Unpin + Clone,
     R::BuiltPayload: Unpin + Clone,
+    Conv: AttributeConverter<L::Attributes, R::Attributes>,
+    Ovr: RegistryOverride<L::Attributes, R::Attributes>,
     <<L as PayloadBuilder<Pool, Client>>::Attributes as PayloadBuilderAttributes>::Error: 'static,
     <<R as PayloadBuilder<Pool, Client>>::Attributes as PayloadBuilderAttributes>::Error: 'static,
 {
@@ -198,8 +349,29 @@ where
         &self,
         args: BuildArguments<Pool, Client, Self::Attributes, Self::BuiltPayload>,
     ) -> Result<BuildOutcome<Self::BuiltPayload>, PayloadBuilderError> {
+        if self.mode == StackMode::Competitive {
+            return match args.config.attributes {
+                Either::Left(ref left_attr) => {
+                    let left_attr = left_attr.clone();
+                    let right_attr = self.converter.left_to_right(&left_attr);
+                    self.try_build_competitive(left_attr, right_attr, &args)
+                }
+                Either::Right(ref right_attr) => {
+                    let right_attr = right_attr.clone();
+                    let left_attr = self.converter.right_to_left(&right_attr);
+                    self.try_build_competitive(left_attr, right_attr, &args)
+                }
+            };
+        }
+
+        let parent_gas_limit = args.config.parent_block.gas_limit;
+
         match args.config.attributes {
             Either::Left(ref left_attr) => {
+                let mut left_attr = left_attr.clone();
+                if let Some(registry) = &self.registry {
+                    left_attr = self.overrider.override_left(registry, left_attr, parent_gas_limit);
+                }
                 let left_args:  BuildArguments<Pool, Client, L::Attributes, L::BuiltPayload> = BuildArguments {
                     client: args.client.clone(),
                     pool: args.pool.clone(),
@@ -207,7 +379,7 @@ where
                     config: PayloadConfig {
                         parent_block: args.config.parent_block.clone(),
                         extra_data: args.config.extra_data.clone(),
-                        attributes: left_attr.clone(),
+                        attributes: left_attr,
                     },
                     cancel: args.cancel.clone(),
                     best_payload: args.best_payload.clone().and_then(|payload| {
@@ -234,6 +406,10 @@ where
                 }
             }
             Either::Right(ref right_attr) => {
+                let mut right_attr = right_attr.clone();
+                if let Some(registry) = &self.registry {
+                    right_attr = self.overrider.override_right(registry, right_attr, parent_gas_limit);
+                }
                 let right_args = BuildArguments {
                     client: args.client.clone(),
                     pool: args.pool.clone(),
@@ -241,7 +417,7 @@ where
                     config: PayloadConfig {
                         parent_block: args.config.parent_block.clone(),
                         extra_data: args.config.extra_data.clone(),
-                        attributes: right_attr.clone(),
+                        attributes: right_attr,
                     },
                     cancel: args.cancel.clone(),
                     best_payload: args.best_payload.clone().and_then(|payload| {
@@ -317,4 +493,188 @@ where
             "Failed to build empty payload with both left and right builders"
         ))))
     }
+}
+
+impl<L, R, Conv, Ovr, Pool, Client> PayloadBuilderStack<L, R, Conv, Ovr>
+where
+    L: PayloadBuilder<Pool, Client> + Unpin + 'static,
+    R: PayloadBuilder<Pool, Client> + Unpin + 'static,
+    Client: Clone,
+    Pool: Clone,
+    L::Attributes: Unpin + Clone,
+    R::Attributes: Unpin + Clone,
+    L::BuiltPayload: Unpin + Clone,
+    R::BuiltPayload: Unpin + Clone,
+    Ovr: RegistryOverride<L::Attributes, R::Attributes>,
+{
+    /// Runs `left` and `right` against the same job and keeps the higher-ranked `BuildOutcome`:
+    /// a `Better` outranks an `Aborted` whatever their fees, and between two outcomes of the
+    /// same kind the higher `fees()` wins (see [`left_outranks_right`]).
+    ///
+    /// Both attempts run sequentially on the caller's thread (`left` then `right`), not
+    /// concurrently, so a competitive build tick costs roughly the sum of both builders'
+    /// wall-clock time rather than the max. For CPU-bound EVM block building that can matter for
+    /// a time-sensitive slot; callers who need the two attempts to race should spawn them
+    /// themselves before calling in, or avoid competitive mode if the added latency isn't
+    /// acceptable.
+    ///
+    /// If a [`ProposerRegistry`] is attached, both sides' attributes are passed through
+    /// [`RegistryOverride::override_left`]/`override_right` before building, exactly as a
+    /// non-competitive build would — which today is a no-op; see [`RegistryOverride`]'s docs.
+    ///
+    /// If only one side produces a usable outcome, that outcome is returned as-is. If both
+    /// sides abort, the `Aborted` outcome with the larger `fees` wins.
+    fn try_build_competitive(
+        &self,
+        left_attr: L::Attributes,
+        right_attr: R::Attributes,
+        args: &BuildArguments<
+            Pool,
+            Client,
+            Either<L::Attributes, R::Attributes>,
+            Either<L::BuiltPayload, R::BuiltPayload>,
+        >,
+    ) -> Result<BuildOutcome<Either<L::BuiltPayload, R::BuiltPayload>>, PayloadBuilderError> {
+        let (left_attr, right_attr) = if let Some(registry) = &self.registry {
+            let parent_gas_limit = args.config.parent_block.gas_limit;
+            (
+                self.overrider.override_left(registry, left_attr, parent_gas_limit),
+                self.overrider.override_right(registry, right_attr, parent_gas_limit),
+            )
+        } else {
+            (left_attr, right_attr)
+        };
+
+        let left_args = BuildArguments {
+            client: args.client.clone(),
+            pool: args.pool.clone(),
+            cached_reads: args.cached_reads.clone(),
+            config: PayloadConfig {
+                parent_block: args.config.parent_block.clone(),
+                extra_data: args.config.extra_data.clone(),
+                attributes: left_attr,
+            },
+            cancel: args.cancel.clone(),
+            best_payload: args.best_payload.clone().and_then(|payload| {
+                if let Either::Left(p) = payload {
+                    Some(p)
+                } else {
+                    None
+                }
+            }),
+        };
+
+        let right_args = BuildArguments {
+            client: args.client.clone(),
+            pool: args.pool.clone(),
+            cached_reads: args.cached_reads.clone(),
+            config: PayloadConfig {
+                parent_block: args.config.parent_block.clone(),
+                extra_data: args.config.extra_data.clone(),
+                attributes: right_attr,
+            },
+            cancel: args.cancel.clone(),
+            best_payload: args.best_payload.clone().and_then(|payload| {
+                if let Either::Right(p) = payload {
+                    Some(p)
+                } else {
+                    None
+                }
+            }),
+        };
+
+        let left_result = self.left.try_build(left_args);
+        let right_result = self.right.try_build(right_args);
+
+        if matches!(left_result, Ok(BuildOutcome::Cancelled))
+            || matches!(right_result, Ok(BuildOutcome::Cancelled))
+        {
+            return Ok(BuildOutcome::Cancelled);
+        }
+
+        let left_candidate = match left_result {
+            Ok(BuildOutcome::Better { payload, cached_reads }) => {
+                let fees = payload.fees();
+                Some((fees, BuildOutcome::Better { payload: Either::Left(payload), cached_reads }))
+            }
+            Ok(BuildOutcome::Aborted { fees, cached_reads }) => {
+                Some((fees, BuildOutcome::Aborted { fees, cached_reads }))
+            }
+            Ok(BuildOutcome::Cancelled) | Err(_) => None,
+        };
+
+        let right_candidate = match right_result {
+            Ok(BuildOutcome::Better { payload, cached_reads }) => {
+                let fees = payload.fees();
+                Some((fees, BuildOutcome::Better { payload: Either::Right(payload), cached_reads }))
+            }
+            Ok(BuildOutcome::Aborted { fees, cached_reads }) => {
+                Some((fees, BuildOutcome::Aborted { fees, cached_reads }))
+            }
+            Ok(BuildOutcome::Cancelled) | Err(_) => None,
+        };
+
+        match (left_candidate, right_candidate) {
+            (Some((left_fees, left_outcome)), Some((right_fees, right_outcome))) => {
+                let left_is_better = matches!(left_outcome, BuildOutcome::Better { .. });
+                let right_is_better = matches!(right_outcome, BuildOutcome::Better { .. });
+                if left_outranks_right(left_is_better, left_fees, right_is_better, right_fees) {
+                    Ok(left_outcome)
+                } else {
+                    Ok(right_outcome)
+                }
+            }
+            (Some((_, outcome)), None) | (None, Some((_, outcome))) => Ok(outcome),
+            (None, None) => Err(PayloadBuilderError::Other(Box::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "both left and right builders failed to build a competitive payload",
+            )))),
+        }
+    }
+}
+
+/// Ranks the left side's competitive candidate against the right's: a [`BuildOutcome::Better`]
+/// always outranks a [`BuildOutcome::Aborted`] regardless of reported fees, since discarding a
+/// successfully built payload in favor of an aborted one is never correct. Only once both sides
+/// are in the same category do the reported `fees` break the tie.
+fn left_outranks_right(
+    left_is_better: bool,
+    left_fees: U256,
+    right_is_better: bool,
+    right_fees: U256,
+) -> bool {
+    (left_is_better, left_fees) >= (right_is_better, right_fees)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn better_outranks_aborted_with_smaller_fees() {
+        assert!(left_outranks_right(true, U256::from(1), false, U256::from(1_000)));
+    }
+
+    #[test]
+    fn aborted_never_outranks_better_even_with_larger_fees() {
+        assert!(!left_outranks_right(false, U256::from(1_000), true, U256::from(1)));
+    }
+
+    #[test]
+    fn both_better_picks_higher_fees() {
+        assert!(left_outranks_right(true, U256::from(2), true, U256::from(1)));
+        assert!(!left_outranks_right(true, U256::from(1), true, U256::from(2)));
+    }
+
+    #[test]
+    fn both_aborted_picks_higher_fees() {
+        assert!(left_outranks_right(false, U256::from(2), false, U256::from(1)));
+        assert!(!left_outranks_right(false, U256::from(1), false, U256::from(2)));
+    }
+
+    #[test]
+    fn equal_fees_in_same_category_favors_left() {
+        assert!(left_outranks_right(true, U256::from(5), true, U256::from(5)));
+        assert!(left_outranks_right(false, U256::from(5), false, U256::from(5)));
+    }
 }
\ No newline at end of file
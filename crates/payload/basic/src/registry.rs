@@ -0,0 +1,134 @@
+//! A proposer-registration registry of fee-recipient and gas-limit preferences, which a
+//! [`crate::builder_stack::PayloadBuilderStack`] can carry for its attribute overrides.
+//!
+//! This only covers storage and the gas-limit clamping: [`ProposerRegistry::register`] is
+//! in-process, called directly with an already-validated [`ProposerRegistration`]. No
+//! validator-registration endpoint (HTTP handler, RPC method, or otherwise) exists yet to
+//! populate it from incoming registrations — wiring one up, including signature verification
+//! against the registering proposer's BLS pubkey, is follow-up work.
+
+use crate::relay::BlsPublicKey;
+use alloy_primitives::Address;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// A single proposer's registration.
+///
+/// Eventually this is meant to arrive through a validator-registration endpoint; today it's
+/// only ever constructed and passed to [`ProposerRegistry::register`] directly by callers, since
+/// no such endpoint exists in this crate yet (see the module docs).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProposerRegistration {
+    /// Fee recipient the proposer wants included blocks to pay.
+    pub fee_recipient: Address,
+    /// Gas limit the proposer wants the block built against.
+    pub gas_limit: u64,
+}
+
+/// Registry of proposer registrations, keyed by the proposer's BLS pubkey.
+///
+/// [`crate::builder_stack::PayloadBuilderStack::with_registry`] attaches a registry, and the stack
+/// hands it to its [`crate::builder_stack::RegistryOverride`] on every build. The stack itself
+/// reads no registration, and the only override in this crate,
+/// [`crate::builder_stack::NoOverride`], leaves attributes unchanged, so a registration does not
+/// change a built payload's fee recipient or gas limit. [`Self::clamp_gas_limit`] applies the
+/// threshold band to a registered gas limit only when it is called directly.
+#[derive(Debug)]
+pub struct ProposerRegistry {
+    registrations: RwLock<HashMap<BlsPublicKey, ProposerRegistration>>,
+    /// Maximum fraction, e.g. `0.05` for 5%, that a registered gas limit may deviate from the
+    /// parent block's gas limit before it gets clamped to the nearest allowed bound.
+    gas_limit_threshold: f64,
+}
+
+impl ProposerRegistry {
+    /// Creates an empty registry with the given gas-limit override threshold (a fraction, e.g.
+    /// `0.05` for a 5% band around the parent block's gas limit).
+    pub fn new(gas_limit_threshold: f64) -> Self {
+        Self { registrations: RwLock::new(HashMap::new()), gas_limit_threshold }
+    }
+
+    /// Records or updates a proposer's registration, as received from a validator-registration
+    /// request.
+    pub fn register(&self, proposer_pubkey: BlsPublicKey, registration: ProposerRegistration) {
+        self.registrations
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .insert(proposer_pubkey, registration);
+    }
+
+    /// Returns the registration for `proposer_pubkey`, if one has been submitted.
+    pub fn get(&self, proposer_pubkey: &BlsPublicKey) -> Option<ProposerRegistration> {
+        self.registrations.read().unwrap_or_else(|err| err.into_inner()).get(proposer_pubkey).copied()
+    }
+
+    /// Clamps `registered_gas_limit` to within [`Self::gas_limit_threshold`] of
+    /// `parent_gas_limit`, logging when a clamp was necessary.
+    pub fn clamp_gas_limit(&self, registered_gas_limit: u64, parent_gas_limit: u64) -> u64 {
+        let band = (parent_gas_limit as f64 * self.gas_limit_threshold) as u64;
+        let lower = parent_gas_limit.saturating_sub(band);
+        let upper = parent_gas_limit.saturating_add(band);
+
+        let clamped = registered_gas_limit.clamp(lower, upper);
+        if clamped != registered_gas_limit {
+            tracing::info!(
+                target: "payload::registry",
+                registered_gas_limit,
+                parent_gas_limit,
+                clamped_gas_limit = clamped,
+                threshold = self.gas_limit_threshold,
+                "registered gas limit outside allowed band, clamping"
+            );
+        }
+
+        clamped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_returns_none_for_unregistered_proposer() {
+        let registry = ProposerRegistry::new(0.05);
+        assert!(registry.get(&BlsPublicKey::repeat_byte(0x11)).is_none());
+    }
+
+    #[test]
+    fn get_returns_registration_after_register() {
+        let registry = ProposerRegistry::new(0.05);
+        let pubkey = BlsPublicKey::repeat_byte(0x11);
+        let registration =
+            ProposerRegistration { fee_recipient: Address::repeat_byte(0x22), gas_limit: 30_000_000 };
+
+        registry.register(pubkey, registration);
+
+        assert_eq!(registry.get(&pubkey), Some(registration));
+    }
+
+    #[test]
+    fn clamp_gas_limit_within_band_is_unchanged() {
+        let registry = ProposerRegistry::new(0.05);
+        assert_eq!(registry.clamp_gas_limit(30_500_000, 30_000_000), 30_500_000);
+    }
+
+    #[test]
+    fn clamp_gas_limit_above_band_is_clamped_to_upper_bound() {
+        let registry = ProposerRegistry::new(0.05);
+        assert_eq!(registry.clamp_gas_limit(40_000_000, 30_000_000), 31_500_000);
+    }
+
+    #[test]
+    fn clamp_gas_limit_below_band_is_clamped_to_lower_bound() {
+        let registry = ProposerRegistry::new(0.05);
+        assert_eq!(registry.clamp_gas_limit(20_000_000, 30_000_000), 28_500_000);
+    }
+
+    #[test]
+    fn clamp_gas_limit_at_exact_boundary_is_unchanged() {
+        let registry = ProposerRegistry::new(0.05);
+        assert_eq!(registry.clamp_gas_limit(31_500_000, 30_000_000), 31_500_000);
+        assert_eq!(registry.clamp_gas_limit(28_500_000, 30_000_000), 28_500_000);
+    }
+}
@@ -0,0 +1,342 @@
+//! Submission of built payloads to external builder relays over the builder HTTP API, so a
+//! reth node can participate as a block builder in a proposer-builder separation (PBS) flow.
+
+use alloy_primitives::{keccak256, Address, Bytes, FixedBytes, B256, U256};
+use reth_payload_primitives::BuiltPayload;
+use reth_primitives::SealedBlock;
+
+use std::fmt;
+use std::time::Duration;
+
+/// A BLS12-381 public key, as used to identify builders and proposers in relay submissions.
+pub type BlsPublicKey = FixedBytes<48>;
+
+/// A BLS12-381 signature over a [`BidTrace`] signing root.
+pub type BlsSignature = FixedBytes<96>;
+
+/// Domain separator mixed into the signing root of a builder bid submission.
+///
+/// This is a placeholder, all-zero value, *not* a real `compute_domain(DOMAIN_APPLICATION_BUILDER,
+/// ...)` result — computing the real value requires a fork version and genesis validators root,
+/// which this crate does not currently thread through. Signatures produced with this domain will
+/// be rejected by a real relay; wire in the real domain before submitting to one.
+pub const BUILDER_DOMAIN: B256 = B256::new([0x00; 32]);
+
+/// Something capable of producing a BLS signature on behalf of a registered builder, without
+/// tying this crate to a particular BLS backend.
+pub trait BuilderSigner: Send + Sync {
+    /// The builder's BLS public key, included in every [`BidTrace`] this signer produces.
+    fn public_key(&self) -> BlsPublicKey;
+
+    /// Signs `signing_root`, returning the resulting BLS signature.
+    fn sign(&self, signing_root: B256) -> BlsSignature;
+}
+
+/// Metadata describing a builder's bid, submitted to relays alongside the execution payload.
+///
+/// Mirrors the `BidTrace` message used by the builder-spec HTTP API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidTrace {
+    /// Slot this bid is for.
+    pub slot: u64,
+    /// Parent hash of the block being built on top of.
+    pub parent_hash: B256,
+    /// Hash of the sealed block contained in this bid.
+    pub block_hash: B256,
+    /// BLS public key of the builder submitting this bid.
+    pub builder_pubkey: BlsPublicKey,
+    /// BLS public key of the proposer this bid is being submitted for.
+    pub proposer_pubkey: BlsPublicKey,
+    /// Fee recipient the proposer registered for this slot.
+    pub proposer_fee_recipient: Address,
+    /// Gas limit of the built block.
+    pub gas_limit: u64,
+    /// Gas used by the built block.
+    pub gas_used: u64,
+    /// Value of the bid, taken from [`BuiltPayload::fees`].
+    pub value: U256,
+}
+
+impl BidTrace {
+    /// Computes a signing root for this `BidTrace` by hashing its fields' flat concatenation.
+    ///
+    /// This is *not* a real SSZ `hash_tree_root` (no merkleization, no chunking/padding to
+    /// 32-byte leaves) — it's a simplified stand-in sufficient for this crate's own bookkeeping.
+    /// A real relay computes the genuine SSZ hash-tree-root and will reject a signature produced
+    /// from this value.
+    pub fn hash_tree_root(&self) -> B256 {
+        let mut leaves = Vec::with_capacity(9);
+        leaves.extend_from_slice(&self.slot.to_le_bytes());
+        leaves.extend_from_slice(self.parent_hash.as_slice());
+        leaves.extend_from_slice(self.block_hash.as_slice());
+        leaves.extend_from_slice(self.builder_pubkey.as_slice());
+        leaves.extend_from_slice(self.proposer_pubkey.as_slice());
+        leaves.extend_from_slice(self.proposer_fee_recipient.as_slice());
+        leaves.extend_from_slice(&self.gas_limit.to_le_bytes());
+        leaves.extend_from_slice(&self.gas_used.to_le_bytes());
+        leaves.extend_from_slice(&self.value.to_le_bytes::<32>());
+        keccak256(leaves)
+    }
+}
+
+/// Computes a domain-separated signing root for a builder bid and signs it.
+///
+/// The signing root mixes [`BidTrace::hash_tree_root`] with [`BUILDER_DOMAIN`]; see both items'
+/// docs for how this currently diverges from the real builder-spec signing routine.
+pub fn sign_builder_message(bid_trace: &BidTrace, signer: &dyn BuilderSigner) -> BlsSignature {
+    let signing_root = keccak256([bid_trace.hash_tree_root().as_slice(), BUILDER_DOMAIN.as_slice()].concat());
+    signer.sign(signing_root)
+}
+
+/// A `BidTrace` plus the execution payload it describes, signed by the builder and ready to be
+/// POSTed to a relay's bid-submission endpoint.
+#[derive(Debug, Clone)]
+pub struct SignedBidSubmission {
+    /// The bid metadata that was signed.
+    pub message: BidTrace,
+    /// The RLP encoding of the sealed block.
+    pub execution_payload: Bytes,
+    /// The builder's signature over `message`.
+    pub signature: BlsSignature,
+}
+
+/// The outcome of submitting a [`SignedBidSubmission`] to a single relay.
+#[derive(Debug, Clone)]
+pub struct RelaySubmissionOutcome {
+    /// Base URL of the relay this outcome applies to.
+    pub relay: String,
+    /// `Ok` if the relay accepted the submission, `Err` with a description otherwise.
+    pub result: Result<(), String>,
+}
+
+/// Marker proving the caller has read and accepted [`RelaySubmitter::new`]'s warning: bid
+/// signatures produced by this subsystem use [`BUILDER_DOMAIN`] and
+/// [`BidTrace::hash_tree_root`], neither of which is builder-spec compatible yet (see their
+/// docs), so every submission will be rejected by a real relay. There's no legitimate way to
+/// construct one other than `NotRelayCompatibleYet`; its only purpose is to force that
+/// acknowledgment at the call site.
+#[derive(Debug, Clone, Copy)]
+pub struct NotRelayCompatibleYet;
+
+/// Submits built payloads to a configurable set of MEV-Boost relays over the builder HTTP API.
+#[derive(Debug, Clone)]
+pub struct RelaySubmitter {
+    client: reqwest::Client,
+    relays: Vec<String>,
+}
+
+impl RelaySubmitter {
+    /// Creates a new `RelaySubmitter` that POSTs bid submissions to each of `relays`.
+    ///
+    /// The `NotRelayCompatibleYet` argument isn't used for anything other than making the caller
+    /// spell out [`NotRelayCompatibleYet`] at the construction site: this submitter's bid
+    /// signing is a placeholder (see [`BUILDER_DOMAIN`] and [`BidTrace::hash_tree_root`]), so
+    /// submissions will be rejected by any real relay until that's wired in. Do not point this
+    /// at a production relay.
+    pub fn new(relays: Vec<String>, _not_relay_compatible_yet: NotRelayCompatibleYet) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(2))
+                .build()
+                .unwrap_or_default(),
+            relays,
+        }
+    }
+
+    /// Assembles a [`BidTrace`] for `payload` (taking `value` from [`BuiltPayload::fees`]),
+    /// signs it, and submits the resulting [`SignedBidSubmission`] to every configured relay
+    /// concurrently.
+    ///
+    /// Failures to submit to an individual relay are logged but do not prevent submission to the
+    /// others; the accepted/rejected status of every relay is returned.
+    pub async fn submit<P: BuiltPayload>(
+        &self,
+        slot: u64,
+        payload: &P,
+        proposer_pubkey: BlsPublicKey,
+        proposer_fee_recipient: Address,
+        signer: &dyn BuilderSigner,
+    ) -> Vec<RelaySubmissionOutcome> {
+        let block: &SealedBlock = payload.block();
+        let bid_trace = BidTrace {
+            slot,
+            parent_hash: block.parent_hash,
+            block_hash: block.hash(),
+            builder_pubkey: signer.public_key(),
+            proposer_pubkey,
+            proposer_fee_recipient,
+            gas_limit: block.gas_limit,
+            gas_used: block.gas_used,
+            value: payload.fees(),
+        };
+
+        let signature = sign_builder_message(&bid_trace, signer);
+        let execution_payload = Bytes::from(alloy_rlp::encode(block));
+        let submission = SignedBidSubmission { message: bid_trace, execution_payload, signature };
+
+        let futures = self.relays.iter().map(|relay| self.submit_to_relay(relay, &submission));
+        futures::future::join_all(futures).await
+    }
+
+    async fn submit_to_relay(
+        &self,
+        relay: &str,
+        submission: &SignedBidSubmission,
+    ) -> RelaySubmissionOutcome {
+        let url = format!("{relay}/relay/v1/builder/blocks");
+        let result = self
+            .client
+            .post(&url)
+            .json(&RelaySubmissionJson::from(submission))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map(drop)
+            .map_err(|err| {
+                tracing::debug!(target: "payload::relay", %relay, %err, "relay rejected bid submission");
+                err.to_string()
+            });
+
+        RelaySubmissionOutcome { relay: relay.to_string(), result }
+    }
+}
+
+/// Wire-format helper for serializing a [`SignedBidSubmission`] to the relay's JSON body.
+#[derive(serde::Serialize)]
+struct RelaySubmissionJson {
+    message: RelayBidTraceJson,
+    execution_payload: Bytes,
+    signature: BlsSignature,
+}
+
+#[derive(serde::Serialize)]
+struct RelayBidTraceJson {
+    slot: u64,
+    parent_hash: B256,
+    block_hash: B256,
+    builder_pubkey: BlsPublicKey,
+    proposer_pubkey: BlsPublicKey,
+    proposer_fee_recipient: Address,
+    gas_limit: u64,
+    gas_used: u64,
+    value: U256,
+}
+
+impl From<&SignedBidSubmission> for RelaySubmissionJson {
+    fn from(submission: &SignedBidSubmission) -> Self {
+        let m = &submission.message;
+        Self {
+            message: RelayBidTraceJson {
+                slot: m.slot,
+                parent_hash: m.parent_hash,
+                block_hash: m.block_hash,
+                builder_pubkey: m.builder_pubkey,
+                proposer_pubkey: m.proposer_pubkey,
+                proposer_fee_recipient: m.proposer_fee_recipient,
+                gas_limit: m.gas_limit,
+                gas_used: m.gas_used,
+                value: m.value,
+            },
+            execution_payload: submission.execution_payload.clone(),
+            signature: submission.signature,
+        }
+    }
+}
+
+impl fmt::Display for RelaySubmissionOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.result {
+            Ok(()) => write!(f, "{}: accepted", self.relay),
+            Err(err) => write!(f, "{}: rejected ({err})", self.relay),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct RecordingSigner {
+        public_key: BlsPublicKey,
+        last_signing_root: std::sync::Mutex<Option<B256>>,
+    }
+
+    impl BuilderSigner for RecordingSigner {
+        fn public_key(&self) -> BlsPublicKey {
+            self.public_key
+        }
+
+        fn sign(&self, signing_root: B256) -> BlsSignature {
+            *self.last_signing_root.lock().unwrap() = Some(signing_root);
+            BlsSignature::repeat_byte(0x77)
+        }
+    }
+
+    fn sample_bid_trace() -> BidTrace {
+        BidTrace {
+            slot: 1,
+            parent_hash: B256::repeat_byte(0x11),
+            block_hash: B256::repeat_byte(0x22),
+            builder_pubkey: BlsPublicKey::repeat_byte(0x33),
+            proposer_pubkey: BlsPublicKey::repeat_byte(0x44),
+            proposer_fee_recipient: Address::repeat_byte(0x55),
+            gas_limit: 30_000_000,
+            gas_used: 21_000,
+            value: U256::from(100u64),
+        }
+    }
+
+    #[test]
+    fn hash_tree_root_is_deterministic() {
+        let bid_trace = sample_bid_trace();
+        assert_eq!(bid_trace.hash_tree_root(), bid_trace.hash_tree_root());
+    }
+
+    #[test]
+    fn hash_tree_root_differs_on_value_change() {
+        let mut bid_trace = sample_bid_trace();
+        let original = bid_trace.hash_tree_root();
+        bid_trace.value = U256::from(101u64);
+        assert_ne!(original, bid_trace.hash_tree_root());
+    }
+
+    #[test]
+    fn sign_builder_message_is_deterministic() {
+        let bid_trace = sample_bid_trace();
+        let signer = RecordingSigner {
+            public_key: BlsPublicKey::repeat_byte(0x66),
+            last_signing_root: std::sync::Mutex::new(None),
+        };
+
+        let first = sign_builder_message(&bid_trace, &signer);
+        let first_root = *signer.last_signing_root.lock().unwrap();
+        let second = sign_builder_message(&bid_trace, &signer);
+        let second_root = *signer.last_signing_root.lock().unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first_root, second_root);
+    }
+
+    #[test]
+    fn new_requires_not_relay_compatible_yet_marker() {
+        let _submitter = RelaySubmitter::new(vec!["https://example.com".to_string()], NotRelayCompatibleYet);
+    }
+
+    #[test]
+    fn sign_builder_message_root_changes_with_bid_trace() {
+        let signer = RecordingSigner {
+            public_key: BlsPublicKey::repeat_byte(0x66),
+            last_signing_root: std::sync::Mutex::new(None),
+        };
+
+        let mut bid_trace = sample_bid_trace();
+        sign_builder_message(&bid_trace, &signer);
+        let first_root = *signer.last_signing_root.lock().unwrap();
+
+        bid_trace.slot += 1;
+        sign_builder_message(&bid_trace, &signer);
+        let second_root = *signer.last_signing_root.lock().unwrap();
+
+        assert_ne!(first_root, second_root);
+    }
+}